@@ -4,39 +4,286 @@ use std::collections::HashMap;
 #[cfg(feature = "humansize")]
 use humansize::{file_size_opts, FileSize};
 use serde_json::value::{to_value, Value};
+#[cfg(feature = "arbitrary_precision")]
+use std::str::FromStr;
 
 use crate::errors::{Error, Result};
 
-/// Returns a plural suffix if the value is not equal to ±1, or a singular
-/// suffix otherwise. The plural suffix defaults to `s` and the singular suffix
-/// defaults to the empty string (i.e nothing).
-pub fn pluralize(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
-    let num = try_get_value!("pluralize", "value", f64, value);
+/// Returns `true` if `s` is a plain decimal string (optional sign, digits, optional
+/// `.` and more digits) with no scientific notation. Under `arbitrary_precision`,
+/// `Number`'s `Display` preserves the source JSON text verbatim, so a value written
+/// as `1.5e1` stays `"1.5e1"` rather than being normalized to `"15"`. The digit-wise
+/// fast paths below only understand plain decimals and must fall back to the `f64`
+/// path for anything else, rather than misreading the exponent as fractional digits.
+#[cfg(feature = "arbitrary_precision")]
+fn is_plain_decimal(s: &str) -> bool {
+    !s.bytes().any(|b| b == b'e' || b == b'E')
+}
 
-    let plural = match args.get("plural") {
-        Some(val) => try_get_value!("pluralize", "plural", String, val),
-        None => "s".to_string(),
+/// Returns `true` if the given decimal string (as produced by `Number`'s `Display`
+/// under `arbitrary_precision`) represents exactly `1` or `-1`, without going through
+/// an `f64` that would lose precision for very large numbers.
+#[cfg(feature = "arbitrary_precision")]
+fn is_abs_one(s: &str) -> bool {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    let mut parts = s.splitn(2, '.');
+    let int_part = parts.next().unwrap_or("").trim_start_matches('0');
+    let frac_part = parts.next().unwrap_or("");
+    int_part == "1" && frac_part.bytes().all(|b| b == b'0')
+}
+
+/// The maximum `precision` that digit-wise helpers (`round_exact`, `to_suffix`) will
+/// act on. Both receive `precision` straight from template input with no other upper
+/// bound, and drive allocations/loops proportional to it, so an unbounded value (e.g.
+/// `precision=2000000000`) would otherwise be a hang/OOM vector.
+const MAX_DIGIT_PRECISION: usize = 100;
+
+/// The rounding direction/tie-break used by `round_exact`, one per `round` `method`.
+#[cfg(feature = "arbitrary_precision")]
+enum RoundMode {
+    /// `common`, `nearest`, `from-zero`: round half away from zero.
+    Nearest,
+    /// `towards-zero`: truncate.
+    TowardsZero,
+    /// `ceil`, `up`: round towards positive infinity.
+    Ceil,
+    /// `floor`, `down`: round towards negative infinity.
+    Floor,
+    /// `half-even`: banker's rounding.
+    HalfEven,
+}
+
+/// Maps a `round` `method` arg to the `RoundMode` `round_exact` understands, or `None`
+/// if the method is unrecognized (letting the caller fall back to the normal `f64`
+/// path, which reports the "unknown method" error).
+#[cfg(feature = "arbitrary_precision")]
+fn round_mode(method: &str) -> Option<RoundMode> {
+    match method {
+        "common" | "nearest" | "from-zero" => Some(RoundMode::Nearest),
+        "towards-zero" => Some(RoundMode::TowardsZero),
+        "ceil" | "up" => Some(RoundMode::Ceil),
+        "floor" | "down" => Some(RoundMode::Floor),
+        "half-even" => Some(RoundMode::HalfEven),
+        _ => None,
+    }
+}
+
+/// Rounds the exact decimal string behind a `Number` (under `arbitrary_precision`) to
+/// `precision` fractional digits using the given `mode`, without ever constructing an
+/// `f64`, so that very large integers and high-precision decimals round-trip
+/// losslessly.
+#[cfg(feature = "arbitrary_precision")]
+fn round_exact(n: &serde_json::Number, precision: usize, mode: RoundMode) -> Result<serde_json::Number> {
+    if precision > MAX_DIGIT_PRECISION {
+        return Err(Error::msg(format!(
+            "Filter `round` received an excessive `precision`: got `{}`, the maximum \
+             supported precision is {}",
+            precision, MAX_DIGIT_PRECISION
+        )));
+    }
+
+    let s = n.to_string();
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.as_str()),
+    };
+    let mut parts = unsigned.splitn(2, '.');
+    let int_part = parts.next().unwrap_or("0");
+    let frac_part = parts.next().unwrap_or("");
+
+    let truncated_frac = if frac_part.len() >= precision {
+        frac_part[..precision].to_string()
+    } else {
+        let mut frac = frac_part.to_string();
+        while frac.len() < precision {
+            frac.push('0');
+        }
+        frac
     };
 
-    let singular = match args.get("singular") {
-        Some(val) => try_get_value!("pluralize", "singular", String, val),
-        None => "".to_string(),
+    let has_more_digits = frac_part.len() > precision;
+    let first_dropped = if has_more_digits { frac_part.as_bytes()[precision] } else { b'0' };
+    let remainder_nonzero = has_more_digits
+        && (first_dropped != b'0' || frac_part[precision + 1..].bytes().any(|b| b != b'0'));
+
+    let round_up = match mode {
+        RoundMode::TowardsZero => false,
+        RoundMode::Ceil => remainder_nonzero && !negative,
+        RoundMode::Floor => remainder_nonzero && negative,
+        RoundMode::Nearest => has_more_digits && first_dropped >= b'5',
+        RoundMode::HalfEven => {
+            if !has_more_digits || first_dropped < b'5' {
+                false
+            } else if first_dropped > b'5' {
+                true
+            } else {
+                let tail_nonzero = frac_part[precision + 1..].bytes().any(|b| b != b'0');
+                if tail_nonzero {
+                    true
+                } else {
+                    let last_digit = if precision == 0 {
+                        int_part.bytes().last().unwrap_or(b'0')
+                    } else {
+                        truncated_frac.bytes().last().unwrap_or(b'0')
+                    };
+                    (last_digit - b'0') % 2 == 1
+                }
+            }
+        }
     };
 
-    // English uses plural when it isn't one
-    if (num.abs() - 1.).abs() > ::std::f64::EPSILON {
-        Ok(to_value(&plural).unwrap())
+    let (int_out, frac_out) = if round_up {
+        let mut digits: Vec<u8> = int_part.bytes().chain(truncated_frac.bytes()).collect();
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+        let split_at = digits.len() - precision;
+        (
+            String::from_utf8(digits[..split_at].to_vec()).unwrap(),
+            String::from_utf8(digits[split_at..].to_vec()).unwrap(),
+        )
     } else {
-        Ok(to_value(&singular).unwrap())
+        (int_part.to_string(), truncated_frac)
+    };
+
+    let out = if precision == 0 { int_out } else { format!("{}.{}", int_out, frac_out) };
+    let sign = if negative { "-" } else { "" };
+    serde_json::Number::from_str(&format!("{}{}", sign, out))
+        .map_err(|e| Error::msg(format!("Filter `round` produced an invalid number: {}", e)))
+}
+
+/// Locales whose CLDR plural rules are implemented as something other than the
+/// English/German "one: n==1, else other" rule.
+const NON_ENGLISH_LIKE_LOCALES: [&str; 3] = ["fr", "ru", "pl"];
+
+/// Maps a non-negative number to a CLDR plural category (`zero`, `one`, `two`, `few`,
+/// `many`, `other`) for the given language code. Unknown locales fall back to the
+/// English rule (`one` when `n == 1`, `other` otherwise).
+fn plural_category(locale: &str, num: f64) -> &'static str {
+    match locale {
+        "fr" => {
+            if num == 0.0 || num == 1.0 {
+                "one"
+            } else {
+                "other"
+            }
+        }
+        // Non-integer counts fall into `other` for both Russian and Polish.
+        "ru" if num.fract() != 0.0 => "other",
+        "pl" if num.fract() != 0.0 => "other",
+        "ru" => {
+            let int = num as i64;
+            let mod10 = int % 10;
+            let mod100 = int % 100;
+            if mod10 == 1 && mod100 != 11 {
+                "one"
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        "pl" => {
+            let int = num as i64;
+            let mod10 = int % 10;
+            let mod100 = int % 100;
+            if int == 1 {
+                "one"
+            } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                "few"
+            } else {
+                "many"
+            }
+        }
+        // English, German, and unknown locales
+        _ => {
+            if (num - 1.0).abs() < ::std::f64::EPSILON {
+                "one"
+            } else {
+                "other"
+            }
+        }
+    }
+}
+
+/// Returns, for the given CLDR plural category, the caller-supplied suffix for that
+/// category, falling back to the `other` suffix for any category not explicitly given
+/// (`one` falls back to the empty string instead, matching the legacy `singular`
+/// default, for backward compatibility).
+fn plural_suffix(args: &HashMap<String, Value>, category: &str) -> Result<String> {
+    let other = match args.get("other").or_else(|| args.get("plural")) {
+        Some(val) => try_get_value!("pluralize", "other", String, val),
+        None => "s".to_string(),
+    };
+
+    match category {
+        "other" => Ok(other),
+        "one" => match args.get("one").or_else(|| args.get("singular")) {
+            Some(val) => Ok(try_get_value!("pluralize", "one", String, val)),
+            None => Ok("".to_string()),
+        },
+        _ => match args.get(category) {
+            Some(val) => Ok(try_get_value!("pluralize", category, String, val)),
+            None => Ok(other),
+        },
     }
 }
 
+/// Returns the CLDR plural category suffix (`zero`, `one`, `two`, `few`, `many` or
+/// `other`) that matches `value` for the given `locale`. `locale` defaults to `en`.
+/// Built-in rule sets are provided for English/German (`one`: n==1), French (`one`:
+/// n==0 or n==1), and the Slavic family (Russian/Polish, using the standard recurrence
+/// on `n mod 10` and `n mod 100`; Polish `one` is n==1 only, so 21 is `many`); unknown
+/// locales fall back to the English rule. Each category's suffix can be overridden with
+/// the matching `zero`/`one`/`two`/`few`/`many`/`other` arg; unspecified categories
+/// default to the `other` suffix, which in turn defaults to `s`, except `one`, which
+/// defaults to the empty string (the legacy `plural`/`singular` args are still honored as
+/// aliases for `other`/`one` for backward compatibility).
+pub fn pluralize(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let locale = match args.get("locale") {
+        Some(val) => try_get_value!("pluralize", "locale", String, val),
+        None => "en".to_string(),
+    };
+
+    #[cfg(feature = "arbitrary_precision")]
+    {
+        if !NON_ENGLISH_LIKE_LOCALES.contains(&locale.as_str()) {
+            if let Value::Number(n) = value {
+                let s = n.to_string();
+                if is_plain_decimal(&s) {
+                    let category = if is_abs_one(&s) { "one" } else { "other" };
+                    return plural_suffix(args, category).map(|s| to_value(&s).unwrap());
+                }
+            }
+        }
+    }
+
+    let num = try_get_value!("pluralize", "value", f64, value);
+    let category = plural_category(&locale, num.abs());
+
+    plural_suffix(args, category).map(|s| to_value(&s).unwrap())
+}
+
 /// Returns a rounded number using the `method` arg and `precision` given.
-/// `method` defaults to `common` which will round to the nearest number.
-/// `ceil` and `floor` are also available as method.
+/// `method` defaults to `common` which will round to the nearest number, with ties
+/// rounding away from zero. `nearest` is an alias of `common`. `ceil`, `floor`, `up`
+/// (alias of `ceil`) and `down` (alias of `floor`) round in the given direction.
+/// `from-zero` rounds ties away from zero and `towards-zero` truncates toward
+/// zero. `half-even` rounds ties to the nearest even digit (banker's rounding),
+/// avoiding the statistical bias of always rounding halves up.
 /// `precision` defaults to `0`, meaning it will round to an integer
 pub fn round(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
-    let num = try_get_value!("round", "value", f64, value);
     let method = match args.get("method") {
         Some(val) => try_get_value!("round", "method", String, val),
         None => "common".to_string(),
@@ -45,25 +292,80 @@ pub fn round(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
         Some(val) => try_get_value!("round", "precision", i32, val),
         None => 0,
     };
+
+    #[cfg(feature = "arbitrary_precision")]
+    {
+        if precision >= 0 {
+            if let (Value::Number(n), Some(mode)) = (value, round_mode(&method)) {
+                if is_plain_decimal(&n.to_string()) {
+                    return round_exact(n, precision as usize, mode)
+                        .map(to_value)
+                        .map(std::result::Result::unwrap);
+                }
+            }
+        }
+    }
+
+    let num = try_get_value!("round", "value", f64, value);
     let multiplier = if precision == 0 { 1.0 } else { 10.0_f64.powi(precision) };
 
     match method.as_ref() {
-        "common" => Ok(to_value((multiplier * num).round() / multiplier).unwrap()),
-        "ceil" => Ok(to_value((multiplier * num).ceil() / multiplier).unwrap()),
-        "floor" => Ok(to_value((multiplier * num).floor() / multiplier).unwrap()),
+        "common" | "nearest" | "from-zero" => {
+            Ok(to_value((multiplier * num).round() / multiplier).unwrap())
+        }
+        "towards-zero" => Ok(to_value((multiplier * num).trunc() / multiplier).unwrap()),
+        "ceil" | "up" => Ok(to_value((multiplier * num).ceil() / multiplier).unwrap()),
+        "floor" | "down" => Ok(to_value((multiplier * num).floor() / multiplier).unwrap()),
+        "half-even" => {
+            let scaled = multiplier * num;
+            let floor = scaled.floor();
+            let diff = scaled - floor;
+            let rounded = if (diff - 0.5).abs() < ::std::f64::EPSILON {
+                if (floor as i64) % 2 == 0 {
+                    floor
+                } else {
+                    floor + 1.0
+                }
+            } else {
+                scaled.round()
+            };
+            Ok(to_value(rounded / multiplier).unwrap())
+        }
         _ => Err(Error::msg(format!(
             "Filter `round` received an incorrect value for arg `method`: got `{:?}`, \
-             only common, ceil and floor are allowed",
+             only common, nearest, ceil, floor, up, down, from-zero, towards-zero and \
+             half-even are allowed",
             method
         ))),
     }
 }
 
-/// Returns a human-readable file size (i.e. '110 MB') from an integer
+/// Returns a human-readable file size (i.e. '110 MB') from an integer.
+/// The `format` arg picks the convention used: `conventional` (default) divides by
+/// 1024 and uses decimal-style labels (i.e. '110 MB'), `decimal` divides by 1000 and
+/// uses SI labels (i.e. '123 MB'), and `binary` divides by 1024 and uses IEC labels
+/// (i.e. '117 MiB').
 #[cfg(feature = "humansize")]
-pub fn filesizeformat(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
+pub fn filesizeformat(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
     let num = try_get_value!("filesizeformat", "value", usize, value);
-    num.file_size(file_size_opts::CONVENTIONAL)
+    let format = match args.get("format") {
+        Some(val) => try_get_value!("filesizeformat", "format", String, val),
+        None => "conventional".to_string(),
+    };
+    let opts = match format.as_ref() {
+        "conventional" => file_size_opts::CONVENTIONAL,
+        "decimal" => file_size_opts::DECIMAL,
+        "binary" => file_size_opts::BINARY,
+        _ => {
+            return Err(Error::msg(format!(
+                "Filter `filesizeformat` received an incorrect value for arg `format`: got \
+                 `{:?}`, only conventional, decimal and binary are allowed",
+                format
+            )))
+        }
+    };
+
+    num.file_size(opts)
         .map_err(|_| {
             Error::msg(format!("Filter `filesizeformat` was called on a negative number: {}", num))
         })
@@ -71,6 +373,105 @@ pub fn filesizeformat(value: &Value, _: &HashMap<String, Value>) -> Result<Value
         .map(std::result::Result::unwrap)
 }
 
+/// The SI/IEC suffixes used by `to_suffix`/`from_suffix`, in increasing order of magnitude.
+const SUFFIXES: [&str; 9] = ["", "K", "M", "G", "T", "P", "E", "Z", "Y"];
+
+/// Returns a short human string for a raw number (i.e. '1.23M'), `numfmt`-style.
+/// The `format` arg picks the base: `si` (default) divides by 1000 per suffix,
+/// `iec` divides by 1024 per suffix and appends an `i` marker (i.e. '1.18Mi').
+/// `precision` controls the number of decimals kept in the mantissa and defaults to `2`.
+pub fn to_suffix(value: &Value, args: &HashMap<String, Value>) -> Result<Value> {
+    let num = try_get_value!("to_suffix", "value", f64, value);
+    let format = match args.get("format") {
+        Some(val) => try_get_value!("to_suffix", "format", String, val),
+        None => "si".to_string(),
+    };
+    let precision = match args.get("precision") {
+        Some(val) => try_get_value!("to_suffix", "precision", usize, val),
+        None => 2,
+    };
+    if precision > MAX_DIGIT_PRECISION {
+        return Err(Error::msg(format!(
+            "Filter `to_suffix` received an excessive `precision`: got `{}`, the maximum \
+             supported precision is {}",
+            precision, MAX_DIGIT_PRECISION
+        )));
+    }
+
+    let (base, marker) = match format.as_ref() {
+        "si" => (1000.0_f64, ""),
+        "iec" => (1024.0_f64, "i"),
+        _ => {
+            return Err(Error::msg(format!(
+                "Filter `to_suffix` received an incorrect value for arg `format`: got `{:?}`, \
+                 only si and iec are allowed",
+                format
+            )))
+        }
+    };
+
+    let scale = 10.0_f64.powi(precision as i32);
+    let mut mantissa = num;
+    let mut suffix_idx = 0;
+    while mantissa.abs() >= base && suffix_idx < SUFFIXES.len() - 1 {
+        mantissa /= base;
+        suffix_idx += 1;
+    }
+    // Rounding the mantissa can push it up to (or past) the next magnitude boundary
+    // (e.g. 999.95 rounds to 1000 at precision 0) without having bumped the suffix
+    // yet, so re-check and re-divide after rounding until it settles.
+    mantissa = (mantissa * scale).round() / scale;
+    while mantissa.abs() >= base && suffix_idx < SUFFIXES.len() - 1 {
+        mantissa /= base;
+        suffix_idx += 1;
+        mantissa = (mantissa * scale).round() / scale;
+    }
+
+    let suffix = SUFFIXES[suffix_idx];
+    let out = if suffix.is_empty() {
+        format!("{:.*}", precision, mantissa)
+    } else {
+        format!("{:.*}{}{}", precision, mantissa, suffix, marker)
+    };
+
+    Ok(to_value(&out).unwrap())
+}
+
+/// Parses a `to_suffix`-style string (i.e. '1.23M' or '1.18Mi') back into a numeric `Value`.
+/// Reads an optional trailing SI/IEC suffix (`K`/`M`/`G`/`T`/`P`/`E`/`Z`/`Y`), an optional
+/// trailing `i` marking the IEC (1024-based) variant, and multiplies the leading float by
+/// the corresponding power of 1000 or 1024.
+pub fn from_suffix(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
+    let s = try_get_value!("from_suffix", "value", String, value);
+    let trimmed = s.trim();
+
+    let (rest, base) = match trimmed.strip_suffix('i') {
+        Some(rest) => (rest, 1024.0_f64),
+        None => (trimmed, 1000.0_f64),
+    };
+
+    let (mantissa_str, power) = match rest.chars().last() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            match SUFFIXES.iter().position(|&suf| suf == c.to_string()) {
+                Some(idx) if idx > 0 => (&rest[..rest.len() - c.len_utf8()], idx as i32),
+                _ => {
+                    return Err(Error::msg(format!(
+                        "Filter `from_suffix` received a value with an unknown suffix: got `{:?}`",
+                        s
+                    )))
+                }
+            }
+        }
+        _ => (rest, 0),
+    };
+
+    let mantissa: f64 = mantissa_str.trim().parse().map_err(|_| {
+        Error::msg(format!("Filter `from_suffix` was called on an invalid value: {:?}", s))
+    })?;
+
+    Ok(to_value(mantissa * base.powi(power)).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +517,96 @@ mod tests {
         assert_eq!(result.unwrap(), to_value("y").unwrap());
     }
 
+    #[test]
+    fn test_pluralize_french_zero_is_singular() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("fr").unwrap());
+        let result = pluralize(&to_value(0).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_french_two_is_plural() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("fr").unwrap());
+        let result = pluralize(&to_value(2).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("s").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_russian_one() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("ru").unwrap());
+        let result = pluralize(&to_value(21).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_russian_few() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("ru").unwrap());
+        let result = pluralize(&to_value(3).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("s").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_russian_few_custom_suffix() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("ru").unwrap());
+        args.insert("few".to_string(), to_value("-few").unwrap());
+        args.insert("many".to_string(), to_value("-many").unwrap());
+        let result = pluralize(&to_value(3).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("-few").unwrap());
+
+        let result = pluralize(&to_value(11).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("-many").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_russian_fractional_is_other() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("ru").unwrap());
+        args.insert("few".to_string(), to_value("-few").unwrap());
+        args.insert("many".to_string(), to_value("-many").unwrap());
+        let result = pluralize(&to_value(2.5).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("s").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_polish_one() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("pl").unwrap());
+        let result = pluralize(&to_value(1).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_polish_twenty_one_is_many() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("pl").unwrap());
+        args.insert("many".to_string(), to_value("-many").unwrap());
+        let result = pluralize(&to_value(21).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("-many").unwrap());
+    }
+
+    #[test]
+    fn test_pluralize_unknown_locale_falls_back_to_english() {
+        let mut args = HashMap::new();
+        args.insert("locale".to_string(), to_value("xx").unwrap());
+        let result = pluralize(&to_value(1).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
     #[test]
     fn test_round_default() {
         let result = round(&to_value(2.1).unwrap(), &HashMap::new());
@@ -170,6 +661,221 @@ mod tests {
         assert_eq!(result.unwrap(), to_value(2.9).unwrap());
     }
 
+    #[test]
+    fn test_round_half_even() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("half-even").unwrap());
+        let result = round(&to_value(2.5).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(2.0).unwrap());
+
+        let result = round(&to_value(3.5).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(4.0).unwrap());
+    }
+
+    #[test]
+    fn test_round_towards_zero() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("towards-zero").unwrap());
+        let result = round(&to_value(-2.9).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(-2.0).unwrap());
+    }
+
+    #[test]
+    fn test_round_from_zero() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("from-zero").unwrap());
+        let result = round(&to_value(-2.5).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(-3.0).unwrap());
+    }
+
+    #[test]
+    fn test_round_unknown_method() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("nope").unwrap());
+        let result = round(&to_value(2.5).unwrap(), &args);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_pluralize_arbitrary_precision_large_int() {
+        let big = serde_json::from_str::<Value>("123456789012345678901234567890").unwrap();
+        let result = pluralize(&big, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("s").unwrap());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_pluralize_arbitrary_precision_one() {
+        let one = serde_json::from_str::<Value>("1.00").unwrap();
+        let result = pluralize(&one, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_pluralize_arbitrary_precision_scientific_notation_falls_back_to_f64() {
+        let one = serde_json::from_str::<Value>("1e0").unwrap();
+        let result = pluralize(&one, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("").unwrap());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_large_int() {
+        let big = serde_json::from_str::<Value>("123456789012345678901234567890").unwrap();
+        let result = round(&big, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().to_string(),
+            "123456789012345678901234567890"
+        );
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_precision() {
+        let mut args = HashMap::new();
+        args.insert("precision".to_string(), to_value(2).unwrap());
+        let value = serde_json::from_str::<Value>("3.14159").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "3.14");
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_excessive_precision_is_rejected() {
+        let mut args = HashMap::new();
+        args.insert("precision".to_string(), to_value(2_000_000_000_u64).unwrap());
+        let value = serde_json::from_str::<Value>("3.14159").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_half_even() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("half-even").unwrap());
+        let value = serde_json::from_str::<Value>("2.5").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "2");
+
+        let value = serde_json::from_str::<Value>("3.5").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "4");
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_ceil_and_floor() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("ceil").unwrap());
+        let value = serde_json::from_str::<Value>("-2.1").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "-2");
+
+        args.insert("method".to_string(), to_value("floor").unwrap());
+        let value = serde_json::from_str::<Value>("-2.1").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "-3");
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_towards_zero() {
+        let mut args = HashMap::new();
+        args.insert("method".to_string(), to_value("towards-zero").unwrap());
+        let value = serde_json::from_str::<Value>("-2.9").unwrap();
+        let result = round(&value, &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().to_string(), "-2");
+    }
+
+    #[cfg(feature = "arbitrary_precision")]
+    #[test]
+    fn test_round_arbitrary_precision_scientific_notation_falls_back_to_f64() {
+        let value = serde_json::from_str::<Value>("1.5e1").unwrap();
+        let result = round(&value, &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(15.0).unwrap());
+    }
+
+    #[test]
+    fn test_to_suffix_si() {
+        let result = to_suffix(&to_value(1_500_000).unwrap(), &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("1.50M").unwrap());
+    }
+
+    #[test]
+    fn test_to_suffix_iec() {
+        let mut args = HashMap::new();
+        args.insert("format".to_string(), to_value("iec").unwrap());
+        let result = to_suffix(&to_value(1_500_000).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("1.43Mi").unwrap());
+    }
+
+    #[test]
+    fn test_to_suffix_precision() {
+        let mut args = HashMap::new();
+        args.insert("precision".to_string(), to_value(0).unwrap());
+        let result = to_suffix(&to_value(1_500_000).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("2M").unwrap());
+    }
+
+    #[test]
+    fn test_to_suffix_rounds_across_magnitude_boundary() {
+        let mut args = HashMap::new();
+        args.insert("precision".to_string(), to_value(0).unwrap());
+        let result = to_suffix(&to_value(999_950).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("1M").unwrap());
+    }
+
+    #[test]
+    fn test_to_suffix_excessive_precision_is_rejected() {
+        let mut args = HashMap::new();
+        args.insert("precision".to_string(), to_value(2_000_000_000_u64).unwrap());
+        let result = to_suffix(&to_value(1_500_000).unwrap(), &args);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_suffix_si() {
+        let result = from_suffix(&to_value("1.5M").unwrap(), &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(1_500_000.0).unwrap());
+    }
+
+    #[test]
+    fn test_from_suffix_iec() {
+        let result = from_suffix(&to_value("1Mi").unwrap(), &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(1_048_576.0).unwrap());
+    }
+
+    #[test]
+    fn test_from_suffix_no_suffix() {
+        let result = from_suffix(&to_value("42").unwrap(), &HashMap::new());
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value(42.0).unwrap());
+    }
+
     #[cfg(feature = "humansize")]
     #[test]
     fn test_filesizeformat() {
@@ -178,4 +884,24 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), to_value("117.74 MB").unwrap());
     }
+
+    #[cfg(feature = "humansize")]
+    #[test]
+    fn test_filesizeformat_decimal() {
+        let mut args = HashMap::new();
+        args.insert("format".to_string(), to_value("decimal").unwrap());
+        let result = filesizeformat(&to_value(123456789).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("123.46 MB").unwrap());
+    }
+
+    #[cfg(feature = "humansize")]
+    #[test]
+    fn test_filesizeformat_binary() {
+        let mut args = HashMap::new();
+        args.insert("format".to_string(), to_value("binary").unwrap());
+        let result = filesizeformat(&to_value(123456789).unwrap(), &args);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), to_value("117.74 MiB").unwrap());
+    }
 }